@@ -31,6 +31,9 @@ const NOT_FOUND_ERROR_CODE: &str = "CM01";
 const LOCAL_VARIABLE_REFERENCED_BEFORE_ASSIGNMENT: &str = "CM03";
 pub(crate) const LOAD_NOT_SUPPORTED_ERROR_CODE: &str = "CM02";
 const CANNOT_IMPORT_ERROR_CODE: &str = "CE02";
+const CANNOT_REASSIGN_IMMUTABLE_ERROR_CODE: &str = "CM04";
+const BINDING_OBJECT_ERROR_CODE: &str = "CM05";
+const CANNOT_DELETE_IMMUTABLE_ERROR_CODE: &str = "CM06";
 
 #[derive(Debug)]
 #[doc(hidden)]
@@ -42,6 +45,15 @@ pub enum EnvironmentError {
     LocalVariableReferencedBeforeAssignment(String),
     /// Cannot import private symbol, i.e. underscore prefixed
     CannotImportPrivateSymbol(String),
+    /// Raised when trying to reassign a binding that was declared immutable, e.g. with
+    /// [`Environment::set_immutable`].
+    CannotReassignImmutable(String),
+    /// Raised when resolving a name through a [`Environment::with_binding_object`] backing
+    /// object fails with something other than the attribute simply not existing.
+    CannotAccessBindingObject(String, ValueError),
+    /// Raised when trying to delete a binding that was declared immutable, e.g. with
+    /// [`Environment::set_immutable`].
+    CannotDeleteImmutable(String),
 }
 
 impl Into<RuntimeError> for EnvironmentError {
@@ -54,6 +66,11 @@ impl Into<RuntimeError> for EnvironmentError {
                 EnvironmentError::LocalVariableReferencedBeforeAssignment(..) => {
                     LOCAL_VARIABLE_REFERENCED_BEFORE_ASSIGNMENT
                 }
+                EnvironmentError::CannotReassignImmutable(..) => {
+                    CANNOT_REASSIGN_IMMUTABLE_ERROR_CODE
+                }
+                EnvironmentError::CannotAccessBindingObject(..) => BINDING_OBJECT_ERROR_CODE,
+                EnvironmentError::CannotDeleteImmutable(..) => CANNOT_DELETE_IMMUTABLE_ERROR_CODE,
             },
             label: match self {
                 EnvironmentError::TryingToMutateFrozenEnvironment => {
@@ -66,6 +83,15 @@ impl Into<RuntimeError> for EnvironmentError {
                 EnvironmentError::CannotImportPrivateSymbol(ref s) => {
                     format!("Symbol '{}' is private", s)
                 }
+                EnvironmentError::CannotReassignImmutable(..) => {
+                    "Cannot reassign immutable binding".to_owned()
+                }
+                EnvironmentError::CannotAccessBindingObject(..) => {
+                    "Error accessing binding object attribute".to_owned()
+                }
+                EnvironmentError::CannotDeleteImmutable(..) => {
+                    "Cannot delete immutable binding".to_owned()
+                }
             },
             message: match self {
                 EnvironmentError::TryingToMutateFrozenEnvironment => {
@@ -78,6 +104,18 @@ impl Into<RuntimeError> for EnvironmentError {
                 EnvironmentError::CannotImportPrivateSymbol(s) => {
                     format!("Cannot import private symbol '{}'", s)
                 }
+                EnvironmentError::CannotReassignImmutable(s) => {
+                    format!("Cannot reassign immutable binding '{}'", s)
+                }
+                EnvironmentError::CannotAccessBindingObject(s, e) => {
+                    format!(
+                        "Error accessing attribute '{}' on binding object: {:?}",
+                        s, e
+                    )
+                }
+                EnvironmentError::CannotDeleteImmutable(s) => {
+                    format!("Cannot delete immutable binding '{}'", s)
+                }
             },
         }
     }
@@ -89,13 +127,83 @@ impl From<EnvironmentError> for ValueError {
     }
 }
 
+/// The operations a scope needs to support to act as an [`Environment`]'s backend. The default
+/// implementation is [`DeclarativeRecord`]; custom backends can be installed with
+/// [`Environment::from_record`].
+pub trait EnvironmentRecord: std::fmt::Debug {
+    /// A name for this environment, used mainly for debugging.
+    fn name(&self) -> String;
+    /// Where objects are garbage collected.
+    fn heap(&self) -> Heap;
+    /// Get the value of the variable `name`, falling through to the parent chain if it is not
+    /// bound in this record.
+    fn get(&self, name: &str) -> Result<Value, EnvironmentError>;
+    /// Set the value of a variable in that environment.
+    fn set(&mut self, name: &str, value: Value, imported: bool) -> Result<(), EnvironmentError>;
+    /// Freeze the environment, all its values will become immutable after that. Returns whether
+    /// it was not already frozen.
+    fn freeze(&mut self) -> bool;
+    /// Whether `name` is bound directly in this record (not including the parent chain).
+    fn has_binding(&self, name: &str) -> bool;
+    /// Return the parent environment (or `None` if there is no parent).
+    fn get_parent(&self) -> Option<Environment>;
+    /// GC roots owned by this record.
+    fn roots(&self) -> Vec<ValueGcStrong>;
+    /// List the names bound directly in this record (not including the parent chain).
+    fn local_names(&self) -> Vec<String>;
+    /// Remove a local binding, returning whether one existed. Must not delete a binding that
+    /// belongs to the parent chain. Refuses on a frozen environment, or with
+    /// [`EnvironmentError::CannotDeleteImmutable`] on a binding created with
+    /// [`set_immutable`](Self::set_immutable).
+    fn delete(&mut self, name: &str) -> Result<bool, EnvironmentError>;
+
+    /// Reserve `name` as a binding without giving it a value yet (its "temporal dead zone"):
+    /// until it is [`set`](Self::set), [`get`](Self::get) fails with
+    /// [`EnvironmentError::LocalVariableReferencedBeforeAssignment`] rather than
+    /// [`EnvironmentError::VariableNotFound`]. Backends with no notion of uninitialized bindings
+    /// can leave this a no-op, in which case `get` keeps reporting the name as not found until
+    /// it is set.
+    fn declare(&mut self, _name: &str) -> Result<(), EnvironmentError> {
+        Ok(())
+    }
+
+    /// Bind `name` to `value` so it can never be reassigned through [`set`](Self::set). Backends
+    /// with no notion of per-binding mutability can fall back to a plain `set`.
+    fn set_immutable(&mut self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.set(name, value, false)
+    }
+
+    /// Create (or overwrite) a mutable binding for `name`, explicitly.
+    fn create_mutable_binding(&mut self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.set(name, value, false)
+    }
+
+    /// Install a backing object to consult for names not otherwise bound in this record.
+    /// Backends that don't support this are free to ignore the call.
+    fn set_binding_object(&mut self, _value: Value) {}
+
+    /// Set the function used to instantiate set literals encountered while evaluating in this
+    /// record.
+    fn set_constructor(&mut self, _constructor: Box<dyn Fn(Vec<Value>) -> ValueResult>) {}
+
+    /// Invoke the set-literal constructor installed with [`set_constructor`](Self::set_constructor),
+    /// if any. Returns the values back if no constructor is installed, so the caller can fall
+    /// through to the parent environment.
+    fn call_constructor(&self, values: Vec<Value>) -> Result<ValueResult, Vec<Value>> {
+        Err(values)
+    }
+
+    /// Keep a dependency environment alive, e.g. one symbols were imported from.
+    fn add_dep(&mut self, _ptr: *const (), _dep: Environment) {}
+}
+
 #[derive(Clone, Debug)]
 pub struct Environment {
-    env: Rc<RefCell<EnvironmentContent>>,
+    env: Rc<RefCell<Box<dyn EnvironmentRecord>>>,
 }
 
 #[derive(Debug)]
-struct EnvironmentContent {
+struct DeclarativeRecord {
     /// A name for this environment, used mainly for debugging.
     name_: String,
     /// Whether the environment is frozen or not.
@@ -110,13 +218,32 @@ struct EnvironmentContent {
     /// List of variable bindings
     ///
     /// `bool` indicates whether value belongs to this environment (`true`) or imported (`false`)
-    variables: HashMap<String, (Value, bool)>,
+    variables: HashMap<String, (BindingState, bool, Mutability)>,
     /// Optional function which can be used to construct set literals (i.e. `{foo, bar}`).
     /// If not set, attempts to use set literals will raise an error.
     set_constructor: SetConstructor,
+    /// Optional backing object consulted by `get` for names not found in `variables`, after
+    /// the local bindings but before falling through to `parent`. Mirrors the "object
+    /// environment record" pattern, where name resolution delegates to an object's properties.
+    binding_object: Option<Value>,
+}
+
+/// Whether a binding in an [`Environment`] can be reassigned with [`Environment::set`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mutability {
+    Mutable,
+    Immutable,
 }
 
-// Newtype so that EnvironmentContent can derive Debug.
+/// The lifecycle of a binding in a [`DeclarativeRecord`]: reserved (`Declared`) before it holds
+/// a value (`Initialized`).
+#[derive(Debug, Clone)]
+enum BindingState {
+    Declared,
+    Initialized(Value),
+}
+
+// Newtype so that DeclarativeRecord can derive Debug.
 struct SetConstructor(Option<Box<dyn Fn(Vec<Value>) -> ValueResult>>);
 
 impl std::fmt::Debug for SetConstructor {
@@ -129,48 +256,214 @@ impl std::fmt::Debug for SetConstructor {
     }
 }
 
+impl DeclarativeRecord {
+    fn new(name: &str, parent: Option<Environment>) -> DeclarativeRecord {
+        DeclarativeRecord {
+            name_: name.to_owned(),
+            frozen: false,
+            parent,
+            deps: HashMap::new(),
+            heap: Heap::new(name),
+            variables: HashMap::new(),
+            set_constructor: SetConstructor(None),
+            binding_object: None,
+        }
+    }
+
+    /// Set the value of a variable in that environment, declaring it with the given mutability.
+    ///
+    /// Reassigning a binding previously created with [`Mutability::Immutable`] is rejected with
+    /// [`EnvironmentError::CannotReassignImmutable`], regardless of the mutability requested here.
+    fn set_with_mutability(
+        &mut self,
+        name: &str,
+        value: Value,
+        imported: bool,
+        mutability: Mutability,
+    ) -> Result<(), EnvironmentError> {
+        if self.frozen {
+            return Err(EnvironmentError::TryingToMutateFrozenEnvironment);
+        }
+        if let Some((_, _, Mutability::Immutable)) = self.variables.get(name) {
+            return Err(EnvironmentError::CannotReassignImmutable(name.to_owned()));
+        }
+        self.variables.insert(
+            name.to_string(),
+            (BindingState::Initialized(value), imported, mutability),
+        );
+        Ok(())
+    }
+}
+
+impl EnvironmentRecord for DeclarativeRecord {
+    fn name(&self) -> String {
+        self.name_.clone()
+    }
+
+    fn heap(&self) -> Heap {
+        self.heap.clone()
+    }
+
+    /// Get the value of the variable `name`
+    fn get(&self, name: &str) -> Result<Value, EnvironmentError> {
+        match self.variables.get(name) {
+            Some((BindingState::Initialized(value), ..)) => return Ok(value.clone()),
+            Some((BindingState::Declared, ..)) => {
+                return Err(EnvironmentError::LocalVariableReferencedBeforeAssignment(
+                    name.to_owned(),
+                ));
+            }
+            None => {}
+        }
+        if let Some(ref object) = self.binding_object {
+            if object.has_attr(name) {
+                return object
+                    .get_attr(name)
+                    .map_err(|e| EnvironmentError::CannotAccessBindingObject(name.to_owned(), e));
+            }
+        }
+        match self.parent {
+            Some(ref p) => p.get(name),
+            None => Err(EnvironmentError::VariableNotFound(name.to_owned())),
+        }
+    }
+
+    /// Set the value of a variable in that environment.
+    fn set(&mut self, name: &str, value: Value, imported: bool) -> Result<(), EnvironmentError> {
+        self.set_with_mutability(name, value, imported, Mutability::Mutable)
+    }
+
+    /// Freeze the environment, all its value will become immutable after that
+    fn freeze(&mut self) -> bool {
+        if !self.frozen {
+            self.frozen = true;
+            for v in self.variables.values_mut() {
+                if let BindingState::Initialized(ref mut value) = v.0 {
+                    value.freeze();
+                }
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    fn has_binding(&self, name: &str) -> bool {
+        self.variables.contains_key(name)
+            || self
+                .binding_object
+                .as_ref()
+                .map_or(false, |object| object.has_attr(name))
+    }
+
+    /// Return the parent environment (or `None` if there is no parent).
+    fn get_parent(&self) -> Option<Environment> {
+        self.parent.clone()
+    }
+
+    fn roots(&self) -> Vec<ValueGcStrong> {
+        self.variables
+            .values()
+            .flat_map(|(state, imported, _)| match state {
+                BindingState::Initialized(v) if !imported => Some(v),
+                _ => None,
+            })
+            .flat_map(Value::to_gc_strong)
+            .collect()
+    }
+
+    fn local_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.variables.keys().cloned().collect();
+        if let Some(ref object) = self.binding_object {
+            names.extend(object.dir_attr());
+        }
+        names
+    }
+
+    fn delete(&mut self, name: &str) -> Result<bool, EnvironmentError> {
+        if self.frozen {
+            return Err(EnvironmentError::TryingToMutateFrozenEnvironment);
+        }
+        if let Some((_, _, Mutability::Immutable)) = self.variables.get(name) {
+            return Err(EnvironmentError::CannotDeleteImmutable(name.to_owned()));
+        }
+        Ok(self.variables.remove(name).is_some())
+    }
+
+    /// Reserve `name` without a value, so a premature `get` reports
+    /// `LocalVariableReferencedBeforeAssignment` instead of `VariableNotFound`. Leaves an
+    /// already-bound name untouched.
+    fn declare(&mut self, name: &str) -> Result<(), EnvironmentError> {
+        if self.frozen {
+            return Err(EnvironmentError::TryingToMutateFrozenEnvironment);
+        }
+        self.variables.entry(name.to_string()).or_insert((
+            BindingState::Declared,
+            false,
+            Mutability::Mutable,
+        ));
+        Ok(())
+    }
+
+    fn set_immutable(&mut self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.set_with_mutability(name, value, false, Mutability::Immutable)
+    }
+
+    fn create_mutable_binding(&mut self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.set_with_mutability(name, value, false, Mutability::Mutable)
+    }
+
+    fn set_binding_object(&mut self, value: Value) {
+        self.binding_object = Some(value);
+    }
+
+    fn set_constructor(&mut self, constructor: Box<dyn Fn(Vec<Value>) -> ValueResult>) {
+        self.set_constructor = SetConstructor(Some(constructor));
+    }
+
+    fn call_constructor(&self, values: Vec<Value>) -> Result<ValueResult, Vec<Value>> {
+        match self.set_constructor.0 {
+            Some(ref ctor) => Ok(ctor(values)),
+            None => Err(values),
+        }
+    }
+
+    fn add_dep(&mut self, ptr: *const (), dep: Environment) {
+        self.deps.insert(ptr, dep);
+    }
+}
+
 impl Environment {
     /// Create a new environment
     pub fn new(name: &str) -> Environment {
-        Environment {
-            env: Rc::new(RefCell::new(EnvironmentContent {
-                name_: name.to_owned(),
-                frozen: false,
-                parent: None,
-                deps: HashMap::new(),
-                heap: Heap::new(name),
-                variables: HashMap::new(),
-                set_constructor: SetConstructor(None),
-            })),
-        }
+        Environment::from_record(Box::new(DeclarativeRecord::new(name, None)))
     }
 
     /// Create a new child environment for this environment
     pub fn child(&self, name: &str) -> Environment {
         self.freeze(true);
+        Environment::from_record(Box::new(DeclarativeRecord::new(name, Some(self.clone()))))
+    }
+
+    /// Create an environment backed by a custom [`EnvironmentRecord`] implementation, e.g. an
+    /// object-backed or lazily-materialized record, instead of the default
+    /// [`DeclarativeRecord`].
+    pub fn from_record(record: Box<dyn EnvironmentRecord>) -> Environment {
         Environment {
-            env: Rc::new(RefCell::new(EnvironmentContent {
-                name_: name.to_owned(),
-                frozen: false,
-                parent: Some(self.clone()),
-                deps: HashMap::new(),
-                heap: Heap::new(name),
-                variables: HashMap::new(),
-                set_constructor: SetConstructor(None),
-            })),
+            env: Rc::new(RefCell::new(record)),
         }
     }
 
     /// Get a heap which stores known objects for this environment.
     pub(crate) fn heap(&self) -> Heap {
-        self.env.borrow().heap.clone()
+        self.env.borrow().heap()
     }
 
     /// Create a new child environment
     /// Freeze the environment, all its value will become immutable after that
     pub fn freeze(&self, gc: bool) -> &Self {
         if self.env.borrow_mut().freeze() {
-            let heap = self.env.borrow().heap.clone();
+            let heap = self.env.borrow().heap();
             if gc {
                 // Note GC on freeze is optional: if it is not called, objects will be dropped
                 // during the heap drop without graph traversal (much faster).
@@ -184,7 +477,7 @@ impl Environment {
 
     /// Return the name of this module
     pub fn name(&self) -> String {
-        self.env.borrow().name_.clone()
+        self.env.borrow().name()
     }
 
     /// Set the value of a variable in that environment.
@@ -192,17 +485,72 @@ impl Environment {
         self.env.borrow_mut().set(name, value, false)
     }
 
+    /// Create (or overwrite) a mutable binding for `name`, explicitly. Equivalent to [`set`](
+    /// Environment::set), provided for symmetry with [`set_immutable`](Environment::set_immutable).
+    pub fn create_mutable_binding(&self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.env.borrow_mut().create_mutable_binding(name, value)
+    }
+
+    /// Bind `name` to `value` so that it can never be reassigned through [`set`](Environment::set),
+    /// without freezing the rest of the environment. Attempts to reassign it later fail with
+    /// [`EnvironmentError::CannotReassignImmutable`].
+    pub fn set_immutable(&self, name: &str, value: Value) -> Result<(), EnvironmentError> {
+        self.env.borrow_mut().set_immutable(name, value)
+    }
+
     /// Get the value of the variable `name`
     pub fn get(&self, name: &str) -> Result<Value, EnvironmentError> {
         self.env.borrow().get(name)
     }
 
+    /// Return whether `name` is visible in this environment, i.e. bound here or in a parent.
+    pub fn has(&self, name: &str) -> bool {
+        if self.env.borrow().has_binding(name) {
+            return true;
+        }
+        match self.get_parent() {
+            Some(ref parent) => parent.has(name),
+            None => false,
+        }
+    }
+
+    /// Remove the local binding `name`, if any, returning whether one existed. Only ever deletes
+    /// a binding owned by this environment; bindings inherited from a parent are left untouched.
+    /// Fails if this environment is frozen, or with
+    /// [`EnvironmentError::CannotDeleteImmutable`] if `name` was bound with
+    /// [`set_immutable`](Environment::set_immutable).
+    pub fn delete(&self, name: &str) -> Result<bool, EnvironmentError> {
+        self.env.borrow_mut().delete(name)
+    }
+
+    /// List the names visible in this environment, including those inherited from parents.
+    pub fn names(&self) -> Vec<String> {
+        let mut names = self.local_names();
+        if let Some(parent) = self.get_parent() {
+            names.extend(parent.names());
+        }
+        names
+    }
+
+    /// List the names bound directly in this environment, not including parents.
+    pub fn local_names(&self) -> Vec<String> {
+        self.env.borrow().local_names()
+    }
+
+    /// Declare `name` as bound in this environment without giving it a value yet. Until it is
+    /// [`set`](Environment::set), [`get`](Environment::get) fails with
+    /// [`EnvironmentError::LocalVariableReferencedBeforeAssignment`] instead of
+    /// [`EnvironmentError::VariableNotFound`], representing hoisting/forward-reference
+    /// semantics where a name is known to exist before it is assigned.
+    pub fn declare(&self, name: &str) -> Result<(), EnvironmentError> {
+        self.env.borrow_mut().declare(name)
+    }
+
     /// Add environment as a dependency
     fn add_env_dep(&self, dep: &Environment) {
         self.env
             .borrow_mut()
-            .deps
-            .insert(dep.env.as_ptr() as *const (), dep.clone());
+            .add_dep(dep.env.as_ptr() as *const (), dep.clone());
     }
 
     pub fn import_symbol(
@@ -240,13 +588,19 @@ impl Environment {
     /// The `Value` returned by this function is expected to be a one-dimensional collection
     /// containing no duplicates.
     pub fn with_set_constructor(&self, constructor: Box<dyn Fn(Vec<Value>) -> ValueResult>) {
-        self.env.borrow_mut().set_constructor = SetConstructor(Some(constructor));
+        self.env.borrow_mut().set_constructor(constructor);
+    }
+
+    /// Install a backing object consulted by [`get`](Environment::get) for names not found
+    /// among this environment's own bindings, before falling through to the parent environment.
+    pub fn with_binding_object(&self, value: Value) {
+        self.env.borrow_mut().set_binding_object(value);
     }
 
     pub(crate) fn make_set(&self, values: Vec<Value>) -> ValueResult {
-        match self.env.borrow().set_constructor.0 {
-            Some(ref ctor) => ctor(values),
-            None => {
+        match self.env.borrow().call_constructor(values) {
+            Ok(result) => result,
+            Err(values) => {
                 if let Some(parent) = self.get_parent() {
                     parent.make_set(values)
                 } else {
@@ -258,61 +612,7 @@ impl Environment {
 
     /** GC roots */
     pub(crate) fn roots(&self) -> Vec<ValueGcStrong> {
-        let content = self.env.borrow();
-        content
-            .variables
-            .values()
-            .flat_map(|(v, imported)| if !imported { Some(v) } else { None })
-            .flat_map(Value::to_gc_strong)
-            .collect()
-    }
-}
-
-impl EnvironmentContent {
-    /// Create a new child environment
-    /// Freeze the environment, all its value will become immutable after that
-    pub fn freeze(&mut self) -> bool {
-        if !self.frozen {
-            self.frozen = true;
-            for v in self.variables.values_mut() {
-                v.0.freeze();
-            }
-            true
-        } else {
-            false
-        }
-    }
-
-    /// Set the value of a variable in that environment.
-    pub fn set(
-        &mut self,
-        name: &str,
-        value: Value,
-        imported: bool,
-    ) -> Result<(), EnvironmentError> {
-        if self.frozen {
-            Err(EnvironmentError::TryingToMutateFrozenEnvironment)
-        } else {
-            self.variables.insert(name.to_string(), (value, imported));
-            Ok(())
-        }
-    }
-
-    /// Get the value of the variable `name`
-    pub fn get(&self, name: &str) -> Result<Value, EnvironmentError> {
-        if self.variables.contains_key(name) {
-            Ok(self.variables[name].0.clone())
-        } else {
-            match self.parent {
-                Some(ref p) => p.get(name),
-                None => Err(EnvironmentError::VariableNotFound(name.to_owned())),
-            }
-        }
-    }
-
-    /// Return the parent environment (or `None` if there is no parent).
-    pub fn get_parent(&self) -> Option<Environment> {
-        self.parent.clone()
+        self.env.borrow().roots()
     }
 }
 
@@ -371,3 +671,154 @@ impl TypeValues {
         self.heap.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declare_then_get_is_temporal_dead_zone() {
+        let env = Environment::new("test");
+        env.declare("x").unwrap();
+        match env.get("x") {
+            Err(EnvironmentError::LocalVariableReferencedBeforeAssignment(name)) => {
+                assert_eq!(name, "x")
+            }
+            other => panic!(
+                "expected referenced-before-assignment error, got {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn declare_then_set_then_get_succeeds() {
+        let env = Environment::new("test");
+        env.declare("x").unwrap();
+        env.set("x", Value::new(1)).unwrap();
+        assert!(env.get("x").is_ok());
+    }
+
+    #[test]
+    fn freeze_skips_declared_bindings() {
+        let env = Environment::new("test");
+        env.declare("x").unwrap();
+        env.freeze(false);
+        match env.get("x") {
+            Err(EnvironmentError::LocalVariableReferencedBeforeAssignment(..)) => {}
+            other => panic!(
+                "expected declared binding to survive freeze, got {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn delete_removes_a_declared_slot() {
+        let env = Environment::new("test");
+        env.declare("x").unwrap();
+        assert!(env.delete("x").unwrap());
+        assert!(!env.has("x"));
+    }
+
+    #[test]
+    fn set_immutable_rejects_reassignment() {
+        let env = Environment::new("test");
+        env.set_immutable("x", Value::new(1)).unwrap();
+        match env.set("x", Value::new(2)) {
+            Err(EnvironmentError::CannotReassignImmutable(name)) => assert_eq!(name, "x"),
+            other => panic!("expected cannot-reassign-immutable error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn get_falls_through_binding_object_to_parent() {
+        let parent = Environment::new("parent");
+        parent.set("y", Value::new(2)).unwrap();
+        let child = parent.child("child");
+        child.with_binding_object(Value::new(1));
+        // "y" is not an attribute of the binding object, so lookup falls through to the parent.
+        assert!(child.get("y").is_ok());
+        assert!(child.has("y"));
+    }
+
+    #[test]
+    fn local_names_includes_set_bindings_with_binding_object_set() {
+        let env = Environment::new("test");
+        env.set("x", Value::new(1)).unwrap();
+        env.with_binding_object(Value::new(2));
+        assert!(env.local_names().contains(&"x".to_owned()));
+    }
+
+    #[derive(Debug)]
+    struct ConstantRecord {
+        value: Value,
+    }
+
+    impl EnvironmentRecord for ConstantRecord {
+        fn name(&self) -> String {
+            "constant".to_owned()
+        }
+
+        fn heap(&self) -> Heap {
+            Heap::new("constant")
+        }
+
+        fn get(&self, _name: &str) -> Result<Value, EnvironmentError> {
+            Ok(self.value.clone())
+        }
+
+        fn set(
+            &mut self,
+            _name: &str,
+            _value: Value,
+            _imported: bool,
+        ) -> Result<(), EnvironmentError> {
+            Err(EnvironmentError::TryingToMutateFrozenEnvironment)
+        }
+
+        fn freeze(&mut self) -> bool {
+            false
+        }
+
+        fn has_binding(&self, _name: &str) -> bool {
+            true
+        }
+
+        fn get_parent(&self) -> Option<Environment> {
+            None
+        }
+
+        fn roots(&self) -> Vec<ValueGcStrong> {
+            Vec::new()
+        }
+
+        fn local_names(&self) -> Vec<String> {
+            vec!["anything".to_owned()]
+        }
+
+        fn delete(&mut self, _name: &str) -> Result<bool, EnvironmentError> {
+            Ok(false)
+        }
+    }
+
+    #[test]
+    fn from_record_uses_a_custom_backend() {
+        let env = Environment::from_record(Box::new(ConstantRecord {
+            value: Value::new(42),
+        }));
+        assert!(env.get("whatever").is_ok());
+        assert!(env.has("whatever"));
+        assert_eq!(env.local_names(), vec!["anything".to_owned()]);
+    }
+
+    #[test]
+    fn delete_rejects_an_immutable_binding() {
+        let env = Environment::new("test");
+        env.set_immutable("x", Value::new(1)).unwrap();
+        match env.delete("x") {
+            Err(EnvironmentError::CannotDeleteImmutable(name)) => assert_eq!(name, "x"),
+            other => panic!("expected cannot-delete-immutable error, got {:?}", other),
+        }
+    }
+}